@@ -0,0 +1,109 @@
+//! Generates a manifest of every `#[no_mangle] extern "C"` function this
+//! crate exports.
+//!
+//! `tests/abi_manifest.rs` diffs the stable manifest against the committed
+//! C header in `include/geneva_ffi_bridge.h` so ABI drift between the Rust
+//! side and the Go-facing header is caught at build time instead of as a
+//! segfault in the host process. `src/experimental.rs` is excluded from the
+//! stable manifest unconditionally, since those symbols are never part of
+//! the frozen default surface; when the `geneva_experimental` feature is
+//! enabled, a second manifest covering just that file is written and
+//! `tests/abi_manifest_experimental.rs` diffs it against
+//! `include/geneva_ffi_bridge_experimental.h`.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+    let src_dir = Path::new(&manifest_dir).join("src");
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
+    let experimental_enabled = env::var_os("CARGO_FEATURE_GENEVA_EXPERIMENTAL").is_some();
+
+    let mut stable_symbols = Vec::new();
+    let mut experimental_symbols = Vec::new();
+    for entry in fs::read_dir(&src_dir).expect("failed to read src directory") {
+        let entry = entry.expect("failed to read src directory entry");
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("rs") {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", path.display());
+        let contents = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("failed to read {}: {e}", path.display()));
+        if path.file_name().and_then(|name| name.to_str()) == Some("experimental.rs") {
+            if experimental_enabled {
+                experimental_symbols.extend(extract_exported_symbols(&contents));
+            }
+            continue;
+        }
+        stable_symbols.extend(extract_exported_symbols(&contents));
+    }
+    stable_symbols.sort();
+    experimental_symbols.sort();
+
+    let manifest_path = Path::new(&out_dir).join("geneva_symbol_manifest.txt");
+    fs::write(&manifest_path, stable_symbols.join("\n") + "\n")
+        .unwrap_or_else(|e| panic!("failed to write {}: {e}", manifest_path.display()));
+
+    let experimental_manifest_path =
+        Path::new(&out_dir).join("geneva_symbol_manifest_experimental.txt");
+    let experimental_contents = if experimental_symbols.is_empty() {
+        String::new()
+    } else {
+        experimental_symbols.join("\n") + "\n"
+    };
+    fs::write(&experimental_manifest_path, experimental_contents).unwrap_or_else(|e| {
+        panic!(
+            "failed to write {}: {e}",
+            experimental_manifest_path.display()
+        )
+    });
+
+    println!("cargo:rerun-if-changed=include/geneva_ffi_bridge.h");
+    println!("cargo:rerun-if-changed=include/geneva_ffi_bridge_experimental.h");
+    println!("cargo:rerun-if-env-changed=CARGO_FEATURE_GENEVA_EXPERIMENTAL");
+}
+
+/// Extracts a normalized `name(params) -> return_ty` signature for every
+/// `#[no_mangle] pub [unsafe] extern "C" fn` found in `contents`.
+fn extract_exported_symbols(contents: &str) -> Vec<String> {
+    let mut symbols = Vec::new();
+    let mut lines = contents.lines().peekable();
+    while let Some(line) = lines.next() {
+        if !line.trim_start().starts_with("#[no_mangle]") {
+            continue;
+        }
+        let mut signature = String::new();
+        for sig_line in lines.by_ref() {
+            signature.push_str(sig_line.trim());
+            signature.push(' ');
+            if sig_line.contains('{') {
+                break;
+            }
+        }
+        if let Some(symbol) = normalize_signature(&signature) {
+            symbols.push(symbol);
+        }
+    }
+    symbols
+}
+
+fn normalize_signature(signature: &str) -> Option<String> {
+    let fn_idx = signature.find("fn ")?;
+    let rest = &signature[fn_idx + 3..];
+    let name_end = rest.find('(')?;
+    let name = rest[..name_end].trim().to_string();
+    let params_start = name_end + 1;
+    let params_end = rest[params_start..].find(')')? + params_start;
+    let params = rest[params_start..params_end].trim().to_string();
+    let after_params = rest[params_end + 1..].trim();
+    let return_ty = after_params
+        .trim_start_matches("->")
+        .trim()
+        .trim_end_matches('{')
+        .trim();
+    let return_ty = if return_ty.is_empty() { "()" } else { return_ty };
+    Some(format!("{name}({params}) -> {return_ty}"))
+}
@@ -0,0 +1,60 @@
+//! Owns the lifecycle of the shared `geneva-uploader-ffi` client handle
+//! used by [`crate::upload::geneva_upload_batch`].
+//!
+//! The real upload entrypoint, `geneva_upload_batch_sync`, takes a client
+//! handle produced by `geneva_client_new`, not a raw byte buffer on its
+//! own, so Go must call [`geneva_client_init`] once during startup (with a
+//! serialized client config) before the first upload.
+
+use std::sync::Mutex;
+
+use geneva_uploader_ffi::GenevaClientHandle;
+
+use crate::error::GenevaErrorCode;
+
+/// Wraps the raw client handle so it can live behind a `Mutex` shared
+/// across threads; geneva-uploader-ffi's handle is safe to reuse
+/// concurrently once created.
+struct ClientHandle(*mut GenevaClientHandle);
+
+unsafe impl Send for ClientHandle {}
+
+static CLIENT: Mutex<Option<ClientHandle>> = Mutex::new(None);
+
+/// Initializes the shared Geneva client from a serialized config blob,
+/// replacing any client set by a previous call.
+///
+/// # Safety
+/// `ptr` must point to at least `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn geneva_client_init(ptr: *const u8, len: usize) -> i32 {
+    crate::panic_guard::guarded_call(|| {
+        if ptr.is_null() {
+            return GenevaErrorCode::InvalidArgument.code();
+        }
+        let handle = geneva_uploader_ffi::geneva_client_new(ptr, len);
+        if handle.is_null() {
+            return GenevaErrorCode::ClientInitFailed.code();
+        }
+        let mut slot = CLIENT
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        *slot = Some(ClientHandle(handle));
+        GenevaErrorCode::Success.code()
+    })
+}
+
+/// Runs `f` with the shared client handle, or returns
+/// `GenevaErrorCode::ClientNotInitialized` if [`geneva_client_init`] hasn't
+/// been called yet.
+pub(crate) fn with_client<R>(
+    f: impl FnOnce(*mut GenevaClientHandle) -> R,
+) -> Result<R, GenevaErrorCode> {
+    let slot = CLIENT
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match slot.as_ref() {
+        Some(handle) => Ok(f(handle.0)),
+        None => Err(GenevaErrorCode::ClientNotInitialized),
+    }
+}
@@ -0,0 +1,153 @@
+//! Optional client-side encryption of upload payloads.
+//!
+//! Regulated deployments may require telemetry batches to be encrypted
+//! before they ever leave the process, using a customer-managed key. When a
+//! key is configured via [`geneva_set_encryption_key`], callers can run a
+//! serialized batch through [`encrypt_batch`] before handing it to the
+//! Geneva transport.
+//!
+//! Each batch is encrypted with AES-256-GCM using a random 96-bit nonce.
+//! The wire format is `[marker byte][nonce (12 bytes)][ciphertext][tag (16 bytes)]`
+//! so the server side can distinguish encrypted blobs from plaintext ones.
+
+use std::sync::Mutex;
+
+use aes_gcm::aead::{Aead, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use rand::RngCore;
+
+use crate::error::GenevaErrorCode;
+
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+
+/// Content-type marker prepended to batches encrypted by this module, so
+/// the server side can tell an encrypted blob apart from a plaintext one.
+pub const ENCRYPTED_CONTENT_MARKER: u8 = 0xE1;
+
+static ENCRYPTION_KEY: Mutex<Option<[u8; KEY_LEN]>> = Mutex::new(None);
+
+/// Sets (or clears, when `ptr` is null) the 32-byte AES-256-GCM key used to
+/// encrypt outgoing batches.
+///
+/// # Safety
+/// `ptr` must point to at least `len` readable bytes, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn geneva_set_encryption_key(ptr: *const u8, len: usize) -> i32 {
+    crate::panic_guard::guarded_call(|| {
+        let mut key_slot = ENCRYPTION_KEY
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if ptr.is_null() {
+            *key_slot = None;
+            return GenevaErrorCode::Success.code();
+        }
+        if len != KEY_LEN {
+            return GenevaErrorCode::EncryptionKeyWrongLength.code();
+        }
+        let slice = std::slice::from_raw_parts(ptr, len);
+        let mut key = [0u8; KEY_LEN];
+        key.copy_from_slice(slice);
+        *key_slot = Some(key);
+        GenevaErrorCode::Success.code()
+    })
+}
+
+/// Encrypts `plaintext` with the configured key, returning
+/// `[marker][nonce][ciphertext || tag]`.
+///
+/// Returns `Err(GenevaErrorCode::EncryptionKeyNotSet)` if no key has been
+/// configured, or `Err(GenevaErrorCode::EncryptionFailed)` if the AES-GCM
+/// seal operation itself fails.
+pub fn encrypt_batch(plaintext: &[u8]) -> Result<Vec<u8>, GenevaErrorCode> {
+    let key_slot = ENCRYPTION_KEY
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let key_bytes = key_slot.ok_or(GenevaErrorCode::EncryptionKeyNotSet)?;
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key_bytes));
+
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce_bytes);
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    let ciphertext = cipher
+        .encrypt(nonce, plaintext)
+        .map_err(|_| GenevaErrorCode::EncryptionFailed)?;
+
+    let mut out = Vec::with_capacity(1 + NONCE_LEN + ciphertext.len());
+    out.push(ENCRYPTED_CONTENT_MARKER);
+    out.extend_from_slice(&nonce_bytes);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+/// Encrypts `batch` via [`encrypt_batch`] if a key has been configured;
+/// otherwise returns it unchanged. This is what the real upload path in
+/// `upload.rs` calls before handing a batch to the Geneva transport, so
+/// encryption is transparent to callers that never set a key.
+pub fn encrypt_batch_if_configured(batch: &[u8]) -> Result<Vec<u8>, GenevaErrorCode> {
+    let key_is_set = ENCRYPTION_KEY
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .is_some();
+    if key_is_set {
+        encrypt_batch(batch)
+    } else {
+        Ok(batch.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_encryption_key_rejects_the_wrong_length() {
+        let short_key = [1u8; 16];
+        assert_eq!(
+            unsafe { geneva_set_encryption_key(short_key.as_ptr(), short_key.len()) },
+            GenevaErrorCode::EncryptionKeyWrongLength.code()
+        );
+    }
+
+    #[test]
+    fn encrypt_batch_round_trips_and_respects_key_lifecycle() {
+        let plaintext = b"span-batch-bytes";
+
+        // No key configured yet: encrypt_batch fails, encrypt_batch_if_configured
+        // passes the batch through unchanged.
+        assert_eq!(
+            unsafe { geneva_set_encryption_key(std::ptr::null(), 0) },
+            GenevaErrorCode::Success.code()
+        );
+        assert_eq!(
+            encrypt_batch(plaintext).unwrap_err(),
+            GenevaErrorCode::EncryptionKeyNotSet
+        );
+        assert_eq!(
+            encrypt_batch_if_configured(plaintext).unwrap(),
+            plaintext.to_vec()
+        );
+
+        // With a key configured, encrypt_batch produces
+        // [marker][nonce][ciphertext || tag] that decrypts back to the
+        // original plaintext under the same key.
+        let key = [7u8; KEY_LEN];
+        assert_eq!(
+            unsafe { geneva_set_encryption_key(key.as_ptr(), key.len()) },
+            GenevaErrorCode::Success.code()
+        );
+
+        let encrypted = encrypt_batch(plaintext).expect("encryption should succeed once a key is set");
+        assert_eq!(encrypted[0], ENCRYPTED_CONTENT_MARKER);
+        let nonce_bytes = &encrypted[1..1 + NONCE_LEN];
+        let ciphertext = &encrypted[1 + NONCE_LEN..];
+
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+        let decrypted = cipher
+            .decrypt(Nonce::from_slice(nonce_bytes), ciphertext)
+            .expect("decryption with the same key should succeed");
+        assert_eq!(decrypted, plaintext);
+    }
+}
@@ -0,0 +1,59 @@
+//! Structured error codes returned across the FFI boundary.
+//!
+//! CGO cannot observe a Rust panic or a `Result`, so every exported function
+//! in this crate other than `geneva_last_error` (which returns the message
+//! pointer) returns a plain `i32` status code. `0` always means success;
+//! every other value identifies a specific failure so Go callers can branch
+//! on it without parsing a string.
+
+/// Status codes returned by the `geneva_*` FFI entrypoints in this crate.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenevaErrorCode {
+    /// The call completed successfully.
+    Success = 0,
+    /// A Rust panic unwound to the FFI boundary and was caught before it
+    /// could cross into Go. The panic message (if any) is available via
+    /// `geneva_last_error`.
+    Panic = -1,
+    /// The shared rate limiter had no tokens available; the caller should
+    /// back off and retry rather than upload immediately.
+    RateLimited = -2,
+    /// A batch was submitted for encryption but no key has been configured
+    /// via `geneva_set_encryption_key`.
+    EncryptionKeyNotSet = -3,
+    /// The key passed to `geneva_set_encryption_key` was not exactly 32
+    /// bytes (AES-256 requires a 256-bit key).
+    EncryptionKeyWrongLength = -4,
+    /// The AES-256-GCM seal operation itself failed.
+    EncryptionFailed = -5,
+    /// A required pointer argument was null.
+    InvalidArgument = -6,
+    /// `geneva_upload_stream_append` or `geneva_upload_stream_end` was
+    /// called without a prior `geneva_upload_stream_begin`.
+    #[cfg(feature = "geneva_experimental")]
+    StreamNotActive = -7,
+    /// `geneva_upload_stream_begin` was called while a stream was already
+    /// in progress.
+    #[cfg(feature = "geneva_experimental")]
+    StreamAlreadyActive = -8,
+    /// `geneva_client_new` returned a null handle.
+    ClientInitFailed = -9,
+    /// `geneva_upload_batch` was called before `geneva_client_init`.
+    ClientNotInitialized = -10,
+    /// `geneva_encode_and_compress_logs` returned a null batch handle.
+    EncodingFailed = -11,
+}
+
+impl GenevaErrorCode {
+    /// Returns the raw `i32` value handed back across the FFI boundary.
+    pub fn code(self) -> i32 {
+        self as i32
+    }
+}
+
+impl From<GenevaErrorCode> for i32 {
+    fn from(err: GenevaErrorCode) -> Self {
+        err.code()
+    }
+}
@@ -0,0 +1,108 @@
+//! Experimental FFI entrypoints, hidden behind the `geneva_experimental`
+//! Cargo feature.
+//!
+//! These symbols are not part of the stable, frozen surface re-exported by
+//! default: they exist so Go builds can opt in to unreleased capabilities
+//! (streaming upload, batch compaction, new auth modes) without those
+//! symbols leaking into stable consumers. `build.rs` never includes this
+//! module's symbols in the stable manifest; it lists them in a separate
+//! experimental manifest only when the feature is enabled, and the corresponding C declarations in
+//! `include/geneva_ffi_bridge_experimental.h` are guarded by
+//! `#ifdef GENEVA_EXPERIMENTAL` so Go code can detect availability at
+//! compile time.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+
+use crate::error::GenevaErrorCode;
+
+static STREAM_BUFFER: Mutex<Option<Vec<u8>>> = Mutex::new(None);
+static AUTH_MODE: AtomicU32 = AtomicU32::new(0);
+
+/// Begins a new streaming upload, accumulating appended chunks until
+/// [`geneva_upload_stream_end`] ends the stream and discards them.
+#[no_mangle]
+pub extern "C" fn geneva_upload_stream_begin() -> i32 {
+    crate::panic_guard::guarded_call(|| {
+        let mut buffer = STREAM_BUFFER
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if buffer.is_some() {
+            return GenevaErrorCode::StreamAlreadyActive.code();
+        }
+        *buffer = Some(Vec::new());
+        GenevaErrorCode::Success.code()
+    })
+}
+
+/// Appends `len` bytes at `ptr` to the in-progress stream started by
+/// [`geneva_upload_stream_begin`].
+///
+/// # Safety
+/// `ptr` must point to at least `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn geneva_upload_stream_append(ptr: *const u8, len: usize) -> i32 {
+    crate::panic_guard::guarded_call(|| {
+        if ptr.is_null() {
+            return GenevaErrorCode::InvalidArgument.code();
+        }
+        let mut buffer = STREAM_BUFFER
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        match buffer.as_mut() {
+            Some(buffer) => {
+                buffer.extend_from_slice(std::slice::from_raw_parts(ptr, len));
+                GenevaErrorCode::Success.code()
+            }
+            None => GenevaErrorCode::StreamNotActive.code(),
+        }
+    })
+}
+
+/// Ends the in-progress stream and discards its accumulated bytes.
+///
+/// This is a stub: it does not yet hand the buffer off to the upload
+/// transport. The streaming surface is still experimental and this entry
+/// point only tracks begin/append/end lifecycle state for now.
+#[no_mangle]
+pub extern "C" fn geneva_upload_stream_end() -> i32 {
+    crate::panic_guard::guarded_call(|| {
+        let mut buffer = STREAM_BUFFER
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if buffer.take().is_none() {
+            return GenevaErrorCode::StreamNotActive.code();
+        }
+        GenevaErrorCode::Success.code()
+    })
+}
+
+/// Validates a serialized batch at `ptr`/`len` for the upcoming compaction
+/// entry point.
+///
+/// This is a stub: it does not yet remove redundant attributes across
+/// spans. For now it only validates the pointer and reports success so Go
+/// callers can wire up the call site ahead of the real implementation.
+///
+/// # Safety
+/// `ptr` must point to at least `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn geneva_compact_batch(ptr: *const u8, len: usize) -> i32 {
+    crate::panic_guard::guarded_call(|| {
+        if ptr.is_null() {
+            return GenevaErrorCode::InvalidArgument.code();
+        }
+        let _batch = std::slice::from_raw_parts(ptr, len);
+        GenevaErrorCode::Success.code()
+    })
+}
+
+/// Stores `mode` as the selected auth mode. The stored value is not read by
+/// the upload path, so uploads are not affected by it.
+#[no_mangle]
+pub extern "C" fn geneva_configure_auth_mode(mode: u32) -> i32 {
+    crate::panic_guard::guarded_call(|| {
+        AUTH_MODE.store(mode, Ordering::SeqCst);
+        GenevaErrorCode::Success.code()
+    })
+}
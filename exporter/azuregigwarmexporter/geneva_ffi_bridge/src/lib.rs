@@ -2,9 +2,38 @@
 //!
 //! This crate provides a simple bridge that re-exports the geneva-uploader-ffi
 //! functionality from the registry package for CGO integration.
+//!
+//! Because Go calls into this crate directly over CGO, a Rust panic must
+//! never be allowed to unwind across the boundary: [`panic_guard`] catches
+//! it and reports it through [`error::GenevaErrorCode`] and
+//! `geneva_last_error` instead.
+
+mod client;
+mod encryption;
+mod error;
+#[cfg(feature = "geneva_experimental")]
+mod experimental;
+mod panic_guard;
+mod rate_limiter;
+mod upload;
+
+pub use client::geneva_client_init;
+pub use encryption::geneva_set_encryption_key;
+pub use error::GenevaErrorCode;
+#[cfg(feature = "geneva_experimental")]
+pub use experimental::{
+    geneva_compact_batch, geneva_configure_auth_mode, geneva_upload_stream_append,
+    geneva_upload_stream_begin, geneva_upload_stream_end,
+};
+pub use panic_guard::geneva_last_error;
+pub use rate_limiter::{geneva_check_rate_limit, geneva_configure_rate_limit};
+pub use upload::geneva_upload_batch;
 
 pub use geneva_uploader_ffi::*;
 
 // Re-export all FFI functions and types for easy access from Go
 // The geneva-uploader-ffi crate from the registry includes all necessary
-// header files and FFI bindings
+// header files and FFI bindings. `geneva_upload_batch` above is this
+// bridge's own entrypoint, not a re-export: geneva-uploader-ffi's real
+// upload symbol is `geneva_upload_batch_sync`, which takes a client handle
+// and an encoded batch rather than raw bytes (see `client` and `upload`).
@@ -0,0 +1,93 @@
+//! Panic-safe wrapper for the FFI boundary.
+//!
+//! A Rust panic unwinding across an `extern "C"` function into Go is
+//! undefined behavior and can corrupt the host runtime. Every exported
+//! function in this crate must run its body through [`guarded_call`] so a
+//! panic is caught at the boundary and converted into
+//! [`GenevaErrorCode::Panic`] instead of propagating.
+
+use std::cell::RefCell;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use crate::error::GenevaErrorCode;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: String) {
+    let message = CString::new(message).unwrap_or_else(|_| {
+        CString::new("panic message contained an interior NUL byte").unwrap()
+    });
+    LAST_ERROR.with(|slot| *slot.borrow_mut() = Some(message));
+}
+
+/// Runs `f`, catching any panic and recording its message for retrieval via
+/// [`geneva_last_error`]. Returns the `i32` status code produced by `f`, or
+/// `GenevaErrorCode::Panic` if `f` panicked.
+///
+/// Every `#[no_mangle] extern "C"` function in this crate should route its
+/// body through this helper rather than call into Rust logic directly.
+pub fn guarded_call<F>(f: F) -> i32
+where
+    F: FnOnce() -> i32,
+{
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(code) => code,
+        Err(payload) => {
+            let message = panic_message(&payload);
+            set_last_error(message);
+            GenevaErrorCode::Panic.code()
+        }
+    }
+}
+
+fn panic_message(payload: &Box<dyn std::any::Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Returns a pointer to the last panic message recorded on this thread, or
+/// `NULL` if no panic has occurred yet. The returned pointer is owned by
+/// the bridge and remains valid until the next call that records a new
+/// error on this thread; Go must copy it out rather than retain it.
+#[no_mangle]
+pub extern "C" fn geneva_last_error() -> *const c_char {
+    LAST_ERROR.with(|slot| {
+        slot.borrow()
+            .as_ref()
+            .map(|message| message.as_ptr())
+            .unwrap_or(ptr::null())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guarded_call_returns_the_inner_result_on_success() {
+        assert_eq!(guarded_call(|| 42), 42);
+    }
+
+    #[test]
+    fn guarded_call_catches_a_panic_and_records_it_as_the_last_error() {
+        let result = guarded_call(|| panic!("boom"));
+        assert_eq!(result, GenevaErrorCode::Panic.code());
+
+        let message = geneva_last_error();
+        assert!(!message.is_null());
+        let message = unsafe { std::ffi::CStr::from_ptr(message) }
+            .to_str()
+            .unwrap();
+        assert_eq!(message, "boom");
+    }
+}
@@ -0,0 +1,140 @@
+//! Leaky-bucket rate limiting for upload calls crossing the FFI boundary.
+//!
+//! CGO callers may fire uploads from many goroutines with no flow control of
+//! their own, so this module gives Go a way to ask "is it safe to upload
+//! right now" before calling into the real upload entrypoint, and to back
+//! off when the answer is no.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::error::GenevaErrorCode;
+
+/// A token bucket shared across all threads making upload calls.
+struct TokenBucket {
+    capacity: u64,
+    refill_tokens_per_interval: u64,
+    interval: Duration,
+    tokens: u64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: u64, refill_tokens_per_interval: u64, interval: Duration) -> Self {
+        Self {
+            capacity,
+            refill_tokens_per_interval,
+            interval,
+            tokens: capacity,
+            last_refill: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        if elapsed < self.interval || self.refill_tokens_per_interval == 0 {
+            return;
+        }
+        let intervals_elapsed = elapsed.as_secs_f64() / self.interval.as_secs_f64();
+        let refilled = (intervals_elapsed.floor() as u64).saturating_mul(self.refill_tokens_per_interval);
+        if refilled > 0 {
+            self.tokens = (self.tokens + refilled).min(self.capacity);
+            self.last_refill = now;
+        }
+    }
+
+    fn try_acquire(&mut self) -> bool {
+        self.refill(Instant::now());
+        if self.tokens == 0 {
+            false
+        } else {
+            self.tokens -= 1;
+            true
+        }
+    }
+}
+
+static RATE_LIMIT_ENABLED: AtomicU64 = AtomicU64::new(0);
+
+static BUCKET: Mutex<Option<TokenBucket>> = Mutex::new(None);
+
+/// Configures the shared rate limiter used by [`geneva_check_rate_limit`].
+///
+/// `capacity` is the maximum burst size; `refill_per_sec` tokens are added
+/// back every second, up to `capacity`. Passing `refill_per_sec == 0`
+/// disables refilling (the bucket drains to zero and stays there).
+#[no_mangle]
+pub extern "C" fn geneva_configure_rate_limit(capacity: u64, refill_per_sec: u64) -> i32 {
+    crate::panic_guard::guarded_call(|| {
+        let mut bucket = BUCKET.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        *bucket = Some(TokenBucket::new(capacity, refill_per_sec, Duration::from_secs(1)));
+        RATE_LIMIT_ENABLED.store(1, Ordering::SeqCst);
+        GenevaErrorCode::Success.code()
+    })
+}
+
+/// Consumes one token from the shared rate limiter if one is available.
+///
+/// Go callers should invoke this immediately before the real upload
+/// entrypoint and treat `GenevaErrorCode::RateLimited` (`-2`) as a signal to retry with
+/// backoff rather than uploading. If the limiter has not been configured
+/// via [`geneva_configure_rate_limit`], this always succeeds.
+#[no_mangle]
+pub extern "C" fn geneva_check_rate_limit() -> i32 {
+    crate::panic_guard::guarded_call(|| {
+        if RATE_LIMIT_ENABLED.load(Ordering::SeqCst) == 0 {
+            return GenevaErrorCode::Success.code();
+        }
+        let mut bucket = BUCKET.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        match bucket.as_mut() {
+            Some(bucket) => {
+                if bucket.try_acquire() {
+                    GenevaErrorCode::Success.code()
+                } else {
+                    GenevaErrorCode::RateLimited.code()
+                }
+            }
+            None => GenevaErrorCode::Success.code(),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_bucket_refills_only_after_the_interval_elapses() {
+        let mut bucket = TokenBucket::new(2, 2, Duration::from_millis(20));
+        assert!(bucket.try_acquire());
+        assert!(bucket.try_acquire());
+        assert!(!bucket.try_acquire());
+
+        std::thread::sleep(Duration::from_millis(25));
+        assert!(bucket.try_acquire());
+        assert!(bucket.try_acquire());
+        assert!(!bucket.try_acquire());
+    }
+
+    #[test]
+    fn token_bucket_never_refills_past_capacity() {
+        let mut bucket = TokenBucket::new(1, 100, Duration::from_millis(1));
+        std::thread::sleep(Duration::from_millis(10));
+        bucket.refill(Instant::now());
+        assert_eq!(bucket.tokens, 1);
+    }
+
+    #[test]
+    fn geneva_rate_limit_ffi_pair_exhausts_then_rejects() {
+        assert_eq!(
+            geneva_configure_rate_limit(1, 0),
+            GenevaErrorCode::Success.code()
+        );
+        assert_eq!(geneva_check_rate_limit(), GenevaErrorCode::Success.code());
+        assert_eq!(
+            geneva_check_rate_limit(),
+            GenevaErrorCode::RateLimited.code()
+        );
+    }
+}
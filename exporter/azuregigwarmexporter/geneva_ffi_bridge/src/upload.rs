@@ -0,0 +1,68 @@
+//! Wraps the real upload entrypoint from geneva-uploader-ffi so every call
+//! crossing the FFI boundary goes through the panic guard, the rate
+//! limiter, and (when a key is configured) encryption before the batch
+//! reaches the Geneva transport.
+//!
+//! geneva-uploader-ffi's real upload entrypoint is
+//! `geneva_upload_batch_sync(handle, batches, index) -> GenevaError`: it
+//! takes a client handle (see [`crate::client`]) and a batch that has
+//! already been encoded via `geneva_encode_and_compress_logs`, not a raw
+//! byte buffer. This function does that client lookup and encoding step on
+//! the caller's behalf, so Go only ever has to pass serialized batch bytes
+//! to a single `geneva_upload_batch(ptr, len)` call, same as before.
+//! `GenevaError` is assumed to be a `#[repr(i32)]` C-ABI enum with `0` as
+//! success, matching every other status code this bridge hands back.
+
+use crate::client;
+use crate::encryption;
+use crate::error::GenevaErrorCode;
+use crate::rate_limiter;
+
+/// Rate-limits, encrypts if a key is configured, encodes via the shared
+/// Geneva client, then forwards the batch to the real
+/// `geneva_uploader_ffi::geneva_upload_batch_sync`, catching any panic
+/// raised along the way instead of letting it unwind into Go.
+///
+/// # Safety
+/// `ptr` must point to at least `len` readable bytes, or be null only when
+/// `len == 0`.
+#[no_mangle]
+pub unsafe extern "C" fn geneva_upload_batch(ptr: *const u8, len: usize) -> i32 {
+    crate::panic_guard::guarded_call(|| {
+        if ptr.is_null() && len != 0 {
+            return GenevaErrorCode::InvalidArgument.code();
+        }
+
+        let limit_status = rate_limiter::geneva_check_rate_limit();
+        if limit_status != GenevaErrorCode::Success.code() {
+            return limit_status;
+        }
+
+        let batch = if ptr.is_null() {
+            &[]
+        } else {
+            std::slice::from_raw_parts(ptr, len)
+        };
+        let payload = match encryption::encrypt_batch_if_configured(batch) {
+            Ok(payload) => payload,
+            Err(code) => return code.code(),
+        };
+
+        let upload_result = client::with_client(|handle| {
+            let batches = geneva_uploader_ffi::geneva_encode_and_compress_logs(
+                handle,
+                payload.as_ptr(),
+                payload.len(),
+            );
+            if batches.is_null() {
+                return GenevaErrorCode::EncodingFailed.code();
+            }
+            geneva_uploader_ffi::geneva_upload_batch_sync(handle, batches, 0) as i32
+        });
+
+        match upload_result {
+            Ok(code) => code,
+            Err(not_initialized) => not_initialized.code(),
+        }
+    })
+}
@@ -0,0 +1,26 @@
+//! Fails the build if the Rust-exported FFI symbols -- including their
+//! parameter and return types, not just their names -- drift from the
+//! committed C header in `include/geneva_ffi_bridge.h`. The Rust-side
+//! manifest is generated at build time by `build.rs`.
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::{parse_c_header, parse_rust_manifest};
+
+const GENERATED_MANIFEST: &str =
+    include_str!(concat!(env!("OUT_DIR"), "/geneva_symbol_manifest.txt"));
+const COMMITTED_HEADER: &str = include_str!("../include/geneva_ffi_bridge.h");
+
+#[test]
+fn exported_symbols_match_committed_header() {
+    let generated = parse_rust_manifest(GENERATED_MANIFEST);
+    let declared = parse_c_header(COMMITTED_HEADER);
+
+    assert_eq!(
+        generated, declared,
+        "exported Rust symbols (name, parameter types, return type) and \
+         include/geneva_ffi_bridge.h have drifted; regenerate the header to \
+         match the #[no_mangle] functions in src/"
+    );
+}
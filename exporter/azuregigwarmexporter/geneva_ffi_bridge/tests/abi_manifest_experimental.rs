@@ -0,0 +1,31 @@
+//! Fails the build if the experimental FFI symbols -- including their
+//! parameter and return types, not just their names -- drift from the
+//! committed header in `include/geneva_ffi_bridge_experimental.h`. Only
+//! compiled when the `geneva_experimental` feature is enabled, since those
+//! symbols don't exist otherwise.
+#![cfg(feature = "geneva_experimental")]
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::{parse_c_header, parse_rust_manifest};
+
+const GENERATED_MANIFEST: &str = include_str!(concat!(
+    env!("OUT_DIR"),
+    "/geneva_symbol_manifest_experimental.txt"
+));
+const COMMITTED_HEADER: &str = include_str!("../include/geneva_ffi_bridge_experimental.h");
+
+#[test]
+fn experimental_symbols_match_committed_header() {
+    let generated = parse_rust_manifest(GENERATED_MANIFEST);
+    let declared = parse_c_header(COMMITTED_HEADER);
+
+    assert_eq!(
+        generated, declared,
+        "exported experimental symbols (name, parameter types, return type) \
+         and include/geneva_ffi_bridge_experimental.h have drifted; \
+         regenerate the header to match the #[no_mangle] functions in \
+         src/experimental.rs"
+    );
+}
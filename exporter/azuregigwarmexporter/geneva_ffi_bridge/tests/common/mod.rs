@@ -0,0 +1,112 @@
+//! Shared signature parsing for the ABI manifest drift tests. Compares
+//! full `(name, parameter types, return type)` signatures rather than just
+//! function names, so a changed argument or return type -- not just a
+//! renamed or removed symbol -- is caught as drift.
+
+use std::collections::BTreeSet;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Signature {
+    pub name: String,
+    pub param_types: Vec<String>,
+    pub return_type: String,
+}
+
+/// Maps a C or Rust spelling of a type to one canonical name (`const` and
+/// spelling differences like `uint64_t` vs `u64` aside) so both sides of
+/// the FFI boundary can be compared directly.
+fn canonical_type(raw: &str) -> String {
+    let stripped = raw.replace("const", "");
+    let pointer_depth = stripped.matches('*').count();
+    let base = stripped.replace('*', "");
+    let base = match base.trim() {
+        "uint8_t" | "u8" => "u8",
+        "uint32_t" | "u32" => "u32",
+        "uint64_t" | "u64" => "u64",
+        "int32_t" | "i32" => "i32",
+        "size_t" | "usize" => "usize",
+        "char" | "c_char" => "c_char",
+        "" | "void" => "void",
+        other => other,
+    };
+    format!("{}{}", "*".repeat(pointer_depth), base)
+}
+
+fn parse_c_params(params: &str) -> Vec<String> {
+    let params = params.trim();
+    if params.is_empty() || params == "void" {
+        return Vec::new();
+    }
+    params
+        .split(',')
+        .map(|param| {
+            // The type is everything up to the trailing identifier (the
+            // parameter name); any `*` directly against the name belongs
+            // to the type, not the name.
+            let name_start = param
+                .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .map(|idx| idx + 1)
+                .unwrap_or(0);
+            canonical_type(&param[..name_start])
+        })
+        .collect()
+}
+
+fn parse_rust_params(params: &str) -> Vec<String> {
+    let params = params.trim();
+    if params.is_empty() {
+        return Vec::new();
+    }
+    params
+        .split(',')
+        .map(|param| canonical_type(param.split(':').nth(1).unwrap_or_default()))
+        .collect()
+}
+
+/// Parses the `name(params) -> return_ty` lines `build.rs` generates.
+pub fn parse_rust_manifest(manifest: &str) -> BTreeSet<Signature> {
+    manifest
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (head, return_ty) = line
+                .split_once("->")
+                .expect("generated manifest line missing `->`");
+            let open = head.find('(').expect("generated manifest line missing `(`");
+            let close = head.rfind(')').expect("generated manifest line missing `)`");
+            Signature {
+                name: head[..open].trim().to_string(),
+                param_types: parse_rust_params(&head[open + 1..close]),
+                return_type: canonical_type(return_ty),
+            }
+        })
+        .collect()
+}
+
+/// Parses the `return_ty name(params);` declarations in a committed C
+/// header, skipping preprocessor directives and comment lines.
+pub fn parse_c_header(header: &str) -> BTreeSet<Signature> {
+    header
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            if !line.contains('(')
+                || line.starts_with('/')
+                || line.starts_with('*')
+                || line.starts_with('#')
+            {
+                return None;
+            }
+            let open = line.find('(')?;
+            let close = line.find(')')?;
+            let before_paren = &line[..open];
+            let name = before_paren.split_whitespace().last()?.trim_start_matches('*');
+            let return_ty = before_paren[..before_paren.len() - name.len()].trim();
+            Some(Signature {
+                name: name.to_string(),
+                param_types: parse_c_params(&line[open + 1..close]),
+                return_type: canonical_type(return_ty),
+            })
+        })
+        .collect()
+}